@@ -0,0 +1,16 @@
+use std::process::Command;
+
+// Embeds the git commit in the binary as RF_GIT_COMMIT when built from a checkout.
+fn main() {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output();
+    if let Ok(output) = output {
+        if output.status.success() {
+            let commit = String::from_utf8_lossy(&output.stdout);
+            println!("cargo:rustc-env=RF_GIT_COMMIT={}", commit.trim());
+        }
+    }
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+}